@@ -0,0 +1,61 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{de::DeserializeOwned, Serialize};
+use tauri::{AppHandle, Manager};
+
+/// Which daily puzzle a cache entry belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PuzzleType {
+    Wordle,
+    Sudoku,
+}
+
+impl PuzzleType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PuzzleType::Wordle => "wordle",
+            PuzzleType::Sudoku => "sudoku",
+        }
+    }
+}
+
+/// On-disk cache of already-fetched daily puzzles, keyed by `(puzzle_type, date)`.
+pub struct PuzzleCache {
+    dir: PathBuf,
+}
+
+impl PuzzleCache {
+    pub fn new(app: &AppHandle) -> Result<Self, String> {
+        let dir = app
+            .path()
+            .app_data_dir()
+            .map_err(|e| format!("Failed to resolve app data dir: {e}"))?
+            .join("puzzle_cache");
+        fs::create_dir_all(&dir).map_err(|e| format!("Failed to create puzzle cache dir: {e}"))?;
+        Ok(Self { dir })
+    }
+
+    fn path_for(&self, puzzle_type: PuzzleType, date: &str) -> PathBuf {
+        self.dir.join(format!("{}-{}.json", puzzle_type.as_str(), date))
+    }
+
+    /// Returns the cached entry for `date`, if one was ever stored.
+    pub fn load<T: DeserializeOwned>(&self, puzzle_type: PuzzleType, date: &str) -> Option<T> {
+        let raw = fs::read_to_string(self.path_for(puzzle_type, date)).ok()?;
+        serde_json::from_str(&raw).ok()
+    }
+
+    /// Persists `value` so future lookups for `(puzzle_type, date)` are served from disk.
+    pub fn store<T: Serialize>(
+        &self,
+        puzzle_type: PuzzleType,
+        date: &str,
+        value: &T,
+    ) -> Result<(), String> {
+        let raw = serde_json::to_string_pretty(value)
+            .map_err(|e| format!("Failed to serialize cache entry: {e}"))?;
+        fs::write(self.path_for(puzzle_type, date), raw)
+            .map_err(|e| format!("Failed to write cache file: {e}"))
+    }
+}
@@ -0,0 +1,92 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::warn;
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+/// How many parse-failure report pairs (`.json` + `.html`) to keep; older ones are pruned.
+const MAX_REPORTS: usize = 20;
+
+static REPORTS_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// A structured record of a scraper parse failure, paired with the raw HTML that
+/// triggered it so selector breakage can be reproduced from a real captured page
+/// instead of guessed at.
+#[derive(Debug, Serialize)]
+struct ParseFailureReport<'a> {
+    url: &'a str,
+    timestamp: u64,
+    step: &'a str,
+    http_status: Option<u16>,
+    detail: &'a str,
+}
+
+/// Resolves the `reports/` directory under the app data dir. Call once from `run()`.
+pub fn init(app: &AppHandle) {
+    if let Ok(dir) = app.path().app_data_dir() {
+        let _ = REPORTS_DIR.set(dir.join("reports"));
+    }
+}
+
+fn reports_dir() -> PathBuf {
+    REPORTS_DIR.get().cloned().unwrap_or_else(|| PathBuf::from("reports"))
+}
+
+/// Writes the raw HTML plus a structured report for a scraper parse failure into
+/// the reports dir. Only enabled in debug builds, mirroring the `cfg!(debug_assertions)`
+/// gate already used for the log plugin in `run()`.
+#[cfg(debug_assertions)]
+pub fn capture_parse_failure(url: &str, step: &str, http_status: Option<u16>, detail: &str, html: &str) {
+    let dir = reports_dir();
+    if let Err(e) = fs::create_dir_all(&dir) {
+        warn!("Failed to create reports dir: {e}");
+        return;
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let slug = step.chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect::<String>();
+    let base = format!("{slug}-{timestamp}");
+
+    let report = ParseFailureReport { url, timestamp, step, http_status, detail };
+    match serde_json::to_string_pretty(&report) {
+        Ok(json) => {
+            if let Err(e) = fs::write(dir.join(format!("{base}.json")), json) {
+                warn!("Failed to write parse-failure report: {e}");
+            }
+        }
+        Err(e) => warn!("Failed to serialize parse-failure report: {e}"),
+    }
+
+    if let Err(e) = fs::write(dir.join(format!("{base}.html")), html) {
+        warn!("Failed to write captured HTML snapshot: {e}");
+    }
+
+    prune_old_reports(&dir);
+}
+
+/// Keeps only the `MAX_REPORTS` most recently written report pairs, deleting the rest.
+#[cfg(debug_assertions)]
+fn prune_old_reports(dir: &Path) {
+    let mut entries: Vec<_> = match fs::read_dir(dir) {
+        Ok(read_dir) => read_dir.filter_map(|e| e.ok()).collect(),
+        Err(_) => return,
+    };
+    if entries.len() <= MAX_REPORTS * 2 {
+        return;
+    }
+
+    entries.sort_by_key(|entry| entry.metadata().and_then(|m| m.modified()).unwrap_or(UNIX_EPOCH));
+    let excess = entries.len() - MAX_REPORTS * 2;
+    for entry in entries.into_iter().take(excess) {
+        let _ = fs::remove_file(entry.path());
+    }
+}
+
+#[cfg(not(debug_assertions))]
+pub fn capture_parse_failure(_url: &str, _step: &str, _http_status: Option<u16>, _detail: &str, _html: &str) {}
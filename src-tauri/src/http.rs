@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use log::warn;
+use reqwest::{Client, Response};
+
+const MAX_ATTEMPTS: u32 = 4;
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(8);
+
+/// Shared HTTP client, built once and handed to Tauri commands via managed state
+/// instead of each scraper standing up its own `reqwest::Client`.
+pub struct HttpClientState(pub Client);
+
+pub fn build_http_client() -> Result<Client, String> {
+    Client::builder()
+        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36")
+        .timeout(Duration::from_secs(20))
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {e}"))
+}
+
+/// Sends a GET request built fresh by `build_request` on every attempt, retrying
+/// transient failures (timeouts, connection errors, 429/5xx) with exponential
+/// backoff and jitter, honoring `Retry-After` when the server sends one.
+pub async fn get_with_retry<F>(client: &Client, build_request: F) -> Result<Response, String>
+where
+    F: Fn(&Client) -> reqwest::RequestBuilder,
+{
+    let mut backoff = BASE_BACKOFF;
+
+    for attempt in 1..=MAX_ATTEMPTS {
+        match build_request(client).send().await {
+            Ok(response) if is_retryable_status(response.status()) => {
+                if attempt == MAX_ATTEMPTS {
+                    return Ok(response);
+                }
+                let wait = retry_after(&response).unwrap_or(backoff) + jitter();
+                warn!(
+                    "Request returned HTTP {} on attempt {attempt}/{MAX_ATTEMPTS}, retrying in {wait:?}",
+                    response.status()
+                );
+                tokio::time::sleep(wait).await;
+                backoff = next_backoff(backoff);
+            }
+            Ok(response) => return Ok(response),
+            Err(e) if attempt < MAX_ATTEMPTS && (e.is_timeout() || e.is_connect()) => {
+                let wait = backoff + jitter();
+                warn!("Request error on attempt {attempt}/{MAX_ATTEMPTS}: {e}, retrying in {wait:?}");
+                tokio::time::sleep(wait).await;
+                backoff = next_backoff(backoff);
+            }
+            Err(e) => return Err(format!("Request failed after {attempt} attempt(s): {e}")),
+        }
+    }
+
+    Err(format!("Request did not succeed after {MAX_ATTEMPTS} attempts"))
+}
+
+/// Whether a response status warrants a retry (server errors and rate-limiting).
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    status.is_server_error() || status.as_u16() == 429
+}
+
+/// Doubles `current`, capped at `MAX_BACKOFF`.
+fn next_backoff(current: Duration) -> Duration {
+    (current * 2).min(MAX_BACKOFF)
+}
+
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// Small jitter so retries from concurrent fetches don't all land on the same tick.
+fn jitter() -> Duration {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    Duration::from_millis((nanos % 250) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+
+    #[test]
+    fn retries_server_errors_and_rate_limiting() {
+        assert!(is_retryable_status(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+    }
+
+    #[test]
+    fn does_not_retry_success_or_client_errors_other_than_429() {
+        assert!(!is_retryable_status(StatusCode::OK));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+        assert!(!is_retryable_status(StatusCode::BAD_REQUEST));
+    }
+
+    #[test]
+    fn next_backoff_doubles_up_to_the_cap() {
+        assert_eq!(next_backoff(BASE_BACKOFF), BASE_BACKOFF * 2);
+        assert_eq!(next_backoff(MAX_BACKOFF / 2), MAX_BACKOFF);
+        assert_eq!(next_backoff(MAX_BACKOFF), MAX_BACKOFF);
+    }
+}
@@ -1,8 +1,19 @@
+mod cache;
+mod diagnostics;
+mod http;
+mod source;
+
+use cache::{PuzzleCache, PuzzleType};
+use http::{get_with_retry, HttpClientState};
 use log::{error, info, warn};
+use reqwest::Client;
 use scraper::{ElementRef, Html, Selector};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::time::Duration;
+use source::{fetch_from_sources, PuzzleSource};
+use std::future::Future;
+use std::pin::Pin;
+use tauri::{AppHandle, Manager, State};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct WordleData {
@@ -21,6 +32,25 @@ pub struct SudokuData {
     pub solution: Vec<Vec<u8>>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SudokuDifficulty {
+    Easy,
+    Medium,
+    #[default]
+    Hard,
+}
+
+impl SudokuDifficulty {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SudokuDifficulty::Easy => "easy",
+            SudokuDifficulty::Medium => "medium",
+            SudokuDifficulty::Hard => "hard",
+        }
+    }
+}
+
 fn collect_compact_text(element: &ElementRef<'_>) -> String {
     element
         .text()
@@ -61,73 +91,121 @@ fn format_puzzle_label(raw: &str) -> String {
     format!("Wordle #{}", trimmed)
 }
 
-async fn get_wordle_answer_impl() -> Result<WordleData, String> {
-    let client = reqwest::Client::builder()
-        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36")
-        .timeout(Duration::from_secs(20))
-        .build()
-        .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
-    let response = client
-        .get("https://wordfinder.yourdictionary.com/wordle/answers/")
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch page: {}", e))?;
+const WORDFINDER_URL: &str = "https://wordfinder.yourdictionary.com/wordle/answers/";
+
+fn parse_wordle_date(raw: &str, today: chrono::NaiveDate) -> String {
+    let trimmed = raw.trim();
+    let lower = trimmed.to_ascii_lowercase();
+    if lower.contains("today") {
+        return today.format("%Y-%m-%d").to_string();
+    }
+    if lower.contains("yesterday") {
+        return (today - chrono::Duration::days(1)).format("%Y-%m-%d").to_string();
+    }
+
+    chrono::NaiveDate::parse_from_str(trimmed, "%B %d, %Y")
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|_| trimmed.to_string())
+}
+
+fn parse_wordle_row(
+    row: &ElementRef<'_>,
+    cell_selector: &Selector,
+    span_selector: &Selector,
+    today: chrono::NaiveDate,
+) -> Option<WordleData> {
+    let mut cells = row.select(cell_selector);
+    let date_cell = cells.next().map(|cell| collect_compact_text(&cell)).unwrap_or_default();
+    let puzzle_cell = cells.next().map(|cell| collect_compact_text(&cell)).unwrap_or_default();
+    let answer_cell = cells.next()?;
+    let word = answer_cell.select(span_selector).find_map(|span| extract_hidden_word(&span))?;
+
+    Some(WordleData {
+        date: parse_wordle_date(&date_cell, today),
+        word,
+        puzzle: format_puzzle_label(&puzzle_cell),
+    })
+}
+
+async fn fetch_wordfinder_html(client: &Client) -> Result<String, String> {
+    let response = get_with_retry(client, |client| client.get(WORDFINDER_URL)).await?;
 
     if !response.status().is_success() {
-        let status_msg = format!("Wordfinder responded with HTTP {}", response.status());
+        let status = response.status();
+        let status_msg = format!("Wordfinder responded with HTTP {}", status);
         error!("{}", status_msg);
+        let body = response.text().await.unwrap_or_default();
+        diagnostics::capture_parse_failure(WORDFINDER_URL, "http_status", Some(status.as_u16()), &status_msg, &body);
         return Err(status_msg);
     }
 
-    let html = response.text().await.map_err(|e| format!("Failed to read response: {}", e))?;
+    response.text().await.map_err(|e| format!("Failed to read response: {}", e))
+}
+
+async fn get_wordle_answer_impl(client: &Client) -> Result<WordleData, String> {
+    let html = fetch_wordfinder_html(client).await?;
     let document = Html::parse_document(&html);
 
     let row_selector = Selector::parse("table tbody tr").map_err(|_| "Failed to parse row selector")?;
     let cell_selector = Selector::parse("td").map_err(|_| "Failed to parse cell selector")?;
     let span_selector = Selector::parse("span[style*=\"display:none\"]").map_err(|_| "Failed to parse hidden span selector")?;
+    let today = chrono::Local::now().date_naive();
 
     for row in document.select(&row_selector) {
-        let mut cells = row.select(&cell_selector);
-        let date_cell = cells.next().map(|cell| collect_compact_text(&cell)).unwrap_or_default();
-        let puzzle_cell = cells
-            .next()
-            .map(|cell| collect_compact_text(&cell))
-            .unwrap_or_default();
-        if let Some(answer_cell) = cells.next() {
-            if let Some(word) = answer_cell
-                .select(&span_selector)
-                .find_map(|span| extract_hidden_word(&span))
-            {
-                let today = chrono::Local::now().format("%Y-%m-%d").to_string();
-                let puzzle = format_puzzle_label(&puzzle_cell);
-                let date_label = if date_cell.to_ascii_lowercase().contains("today") {
-                    today
-                } else {
-                    today
-                };
-
-                return Ok(WordleData {
-                    date: date_label,
-                    word,
-                    puzzle,
-                });
-            }
+        if let Some(data) = parse_wordle_row(&row, &cell_selector, &span_selector, today) {
+            return Ok(data);
         }
     }
 
     warn!("Wordfinder page parsed but no hidden span containing today's answer was found");
+    diagnostics::capture_parse_failure(
+        WORDFINDER_URL,
+        "hidden_span_not_found",
+        None,
+        "No hidden span containing a five-letter answer was found in any row",
+        &html,
+    );
     Err("Could not find Wordle answer on page".to_string())
 }
 
-fn extract_game_data_blob(html: &str) -> Result<String, String> {
-    const MARKER: &str = "window.gameData = ";
+async fn fetch_wordle_archive_impl(client: &Client) -> Result<Vec<WordleData>, String> {
+    let html = fetch_wordfinder_html(client).await?;
+    let document = Html::parse_document(&html);
+
+    let row_selector = Selector::parse("table tbody tr").map_err(|_| "Failed to parse row selector")?;
+    let cell_selector = Selector::parse("td").map_err(|_| "Failed to parse cell selector")?;
+    let span_selector = Selector::parse("span[style*=\"display:none\"]").map_err(|_| "Failed to parse hidden span selector")?;
+    let today = chrono::Local::now().date_naive();
+
+    let entries: Vec<WordleData> = document
+        .select(&row_selector)
+        .filter_map(|row| parse_wordle_row(&row, &cell_selector, &span_selector, today))
+        .collect();
+
+    if entries.is_empty() {
+        warn!("Wordfinder page parsed but no rows yielded a valid Wordle answer");
+        diagnostics::capture_parse_failure(
+            WORDFINDER_URL,
+            "archive_empty",
+            None,
+            "No rows in the table yielded a five-letter hidden answer",
+            &html,
+        );
+        return Err("Could not find any Wordle answers on page".to_string());
+    }
+
+    Ok(entries)
+}
+
+fn extract_embedded_json(html: &str, var_name: &str) -> Result<String, String> {
+    let marker = format!("window.{var_name} = ");
     let start = html
-        .find(MARKER)
-        .ok_or_else(|| "window.gameData marker not found".to_string())?;
-    let after_marker = &html[start + MARKER.len()..];
+        .find(&marker)
+        .ok_or_else(|| format!("window.{var_name} marker not found"))?;
+    let after_marker = &html[start + marker.len()..];
     let end = after_marker
         .find("</script>")
-        .ok_or_else(|| "Unable to find </script> following window.gameData".to_string())?;
+        .ok_or_else(|| format!("Unable to find </script> following window.{var_name}"))?;
     let raw_block = after_marker[..end].trim();
     Ok(raw_block.trim_end_matches(';').trim().to_string())
 }
@@ -160,24 +238,19 @@ fn board_from_json(value: &Value, label: &str) -> Result<Vec<Vec<u8>>, String> {
         .collect::<Vec<_>>())
 }
 
-async fn fetch_sudoku_puzzle_impl() -> Result<SudokuData, String> {
-    let client = reqwest::Client::builder()
-        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36")
-        .timeout(Duration::from_secs(20))
-        .build()
-        .map_err(|e| format!("Failed to build HTTP client: {e}"))?;
-    info!("Fetching latest Sudoku puzzle from NYT");
-
-    let response = client
-        .get("https://www.nytimes.com/puzzles/sudoku/hard")
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch Sudoku page: {e}"))?;
+async fn fetch_sudoku_puzzle_impl(client: &Client, difficulty: SudokuDifficulty) -> Result<SudokuData, String> {
+    const NYT_SUDOKU_URL: &str = "https://www.nytimes.com/puzzles/sudoku/hard";
+    info!("Fetching latest {} Sudoku puzzle from NYT", difficulty.as_str());
+
+    let response = get_with_retry(client, |client| client.get(NYT_SUDOKU_URL)).await?;
     info!("Fetching latest Wordle answer from Wordfinder");
 
     if !response.status().is_success() {
-        let status_msg = format!("NYT Sudoku responded with HTTP {}", response.status());
+        let status = response.status();
+        let status_msg = format!("NYT Sudoku responded with HTTP {}", status);
         error!("{status_msg}");
+        let body = response.text().await.unwrap_or_default();
+        diagnostics::capture_parse_failure(NYT_SUDOKU_URL, "http_status", Some(status.as_u16()), &status_msg, &body);
         return Err(status_msg);
     }
 
@@ -186,9 +259,21 @@ async fn fetch_sudoku_puzzle_impl() -> Result<SudokuData, String> {
         .await
         .map_err(|e| format!("Failed to read Sudoku response: {e}"))?;
     info!("Fetched latest Sudoku puzzle from NYT");
-    let json_blob = extract_game_data_blob(&html)?;
-    let root: Value = serde_json::from_str(&json_blob)
-        .map_err(|e| format!("Failed to parse gameData JSON: {e}"))?;
+    let json_blob = match extract_embedded_json(&html, "gameData") {
+        Ok(blob) => blob,
+        Err(e) => {
+            diagnostics::capture_parse_failure(NYT_SUDOKU_URL, "game_data_marker_missing", None, &e, &html);
+            return Err(e);
+        }
+    };
+    let root: Value = match serde_json::from_str(&json_blob) {
+        Ok(root) => root,
+        Err(e) => {
+            let detail = format!("Failed to parse gameData JSON: {e}");
+            diagnostics::capture_parse_failure(NYT_SUDOKU_URL, "game_data_json_invalid", None, &detail, &html);
+            return Err(detail);
+        }
+    };
 
     let display_date = root
         .get("displayDate")
@@ -196,55 +281,160 @@ async fn fetch_sudoku_puzzle_impl() -> Result<SudokuData, String> {
         .unwrap_or("")
         .to_string();
 
-    let hard = root
-        .get("hard")
-        .ok_or_else(|| "Missing hard puzzle block".to_string())?;
-    let difficulty = hard
+    let block = root.get(difficulty.as_str()).ok_or_else(|| {
+        let detail = format!("Missing {} puzzle block", difficulty.as_str());
+        diagnostics::capture_parse_failure(NYT_SUDOKU_URL, "difficulty_block_missing", None, &detail, &html);
+        detail
+    })?;
+    let difficulty_label = block
         .get("difficulty")
         .and_then(|v| v.as_str())
         .unwrap_or("Hard")
         .to_string();
-    let print_date = hard
+    let print_date = block
         .get("print_date")
         .and_then(|v| v.as_str())
         .unwrap_or(&display_date)
         .to_string();
-    let puzzle_data = hard
-        .get("puzzle_data")
-        .ok_or_else(|| "Missing puzzle_data block".to_string())?;
-
-    let puzzle = board_from_json(
-        puzzle_data
-            .get("puzzle")
-            .ok_or_else(|| "Missing puzzle array".to_string())?,
-        "puzzle",
-    )?;
-    let solution = board_from_json(
-        puzzle_data
-            .get("solution")
-            .ok_or_else(|| "Missing solution array".to_string())?,
-        "solution",
-    )?;
+    let puzzle_data = block.get("puzzle_data").ok_or_else(|| {
+        let detail = "Missing puzzle_data block".to_string();
+        diagnostics::capture_parse_failure(NYT_SUDOKU_URL, "puzzle_data_missing", None, &detail, &html);
+        detail
+    })?;
+
+    let puzzle_array = puzzle_data.get("puzzle").ok_or_else(|| {
+        let detail = "Missing puzzle array".to_string();
+        diagnostics::capture_parse_failure(NYT_SUDOKU_URL, "puzzle_array_missing", None, &detail, &html);
+        detail
+    })?;
+    let puzzle = board_from_json(puzzle_array, "puzzle").map_err(|e| {
+        diagnostics::capture_parse_failure(NYT_SUDOKU_URL, "board_shape_invalid", None, &e, &html);
+        e
+    })?;
+    let solution_array = puzzle_data.get("solution").ok_or_else(|| {
+        let detail = "Missing solution array".to_string();
+        diagnostics::capture_parse_failure(NYT_SUDOKU_URL, "solution_array_missing", None, &detail, &html);
+        detail
+    })?;
+    let solution = board_from_json(solution_array, "solution").map_err(|e| {
+        diagnostics::capture_parse_failure(NYT_SUDOKU_URL, "board_shape_invalid", None, &e, &html);
+        e
+    })?;
 
     Ok(SudokuData {
         display_date,
         print_date,
-        difficulty,
+        difficulty: difficulty_label,
         puzzle,
         solution,
     })
 }
 
+struct WordfinderSource;
+
+impl PuzzleSource<WordleData> for WordfinderSource {
+    fn name(&self) -> &'static str {
+        "wordfinder.yourdictionary.com"
+    }
+
+    fn fetch<'a>(&'a self, client: &'a Client) -> Pin<Box<dyn Future<Output = Result<WordleData, String>> + Send + 'a>> {
+        Box::pin(async move { get_wordle_answer_impl(client).await })
+    }
+}
+
+struct NytSudokuSource {
+    difficulty: SudokuDifficulty,
+}
+
+impl PuzzleSource<SudokuData> for NytSudokuSource {
+    fn name(&self) -> &'static str {
+        "nytimes.com/puzzles/sudoku"
+    }
+
+    fn fetch<'a>(&'a self, client: &'a Client) -> Pin<Box<dyn Future<Output = Result<SudokuData, String>> + Send + 'a>> {
+        Box::pin(async move { fetch_sudoku_puzzle_impl(client, self.difficulty).await })
+    }
+}
+
+struct WordfinderArchiveSource;
+
+impl PuzzleSource<Vec<WordleData>> for WordfinderArchiveSource {
+    fn name(&self) -> &'static str {
+        "wordfinder.yourdictionary.com (archive)"
+    }
+
+    fn fetch<'a>(&'a self, client: &'a Client) -> Pin<Box<dyn Future<Output = Result<Vec<WordleData>, String>> + Send + 'a>> {
+        Box::pin(async move { fetch_wordle_archive_impl(client).await })
+    }
+}
+
+fn wordle_sources() -> Vec<Box<dyn PuzzleSource<WordleData>>> {
+    vec![Box::new(WordfinderSource)]
+}
+
+fn wordle_archive_sources() -> Vec<Box<dyn PuzzleSource<Vec<WordleData>>>> {
+    vec![Box::new(WordfinderArchiveSource)]
+}
+
+fn sudoku_sources(difficulty: SudokuDifficulty) -> Vec<Box<dyn PuzzleSource<SudokuData>>> {
+    vec![Box::new(NytSudokuSource { difficulty })]
+}
+
 #[tauri::command]
-async fn fetch_wordle_answer() -> Result<WordleData, String> {
+async fn fetch_wordle_answer(
+    app: AppHandle,
+    state: State<'_, HttpClientState>,
+    force_refresh: Option<bool>,
+) -> Result<WordleData, String> {
+    let cache = PuzzleCache::new(&app)?;
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+
+    if !force_refresh.unwrap_or(false) {
+        if let Some(cached) = cache.load::<WordleData>(PuzzleType::Wordle, &today) {
+            info!("Serving Wordle answer for {today} from cache");
+            return Ok(cached);
+        }
+    }
+
     info!("Fetching latest Wordle answer from Wordfinder");
-    get_wordle_answer_impl().await
+    let data = fetch_from_sources(&wordle_sources(), &state.0).await?;
+    if let Err(e) = cache.store(PuzzleType::Wordle, &data.date, &data) {
+        warn!("Failed to cache Wordle answer for {}: {e}", data.date);
+    }
+    Ok(data)
 }
 
 #[tauri::command]
-async fn fetch_sudoku_puzzle() -> Result<SudokuData, String> {
-    info!("Fetching NYT hard Sudoku puzzle");
-    fetch_sudoku_puzzle_impl().await
+async fn fetch_wordle_archive(state: State<'_, HttpClientState>) -> Result<Vec<WordleData>, String> {
+    info!("Fetching full Wordle archive from Wordfinder");
+    fetch_from_sources(&wordle_archive_sources(), &state.0).await
+}
+
+#[tauri::command]
+async fn fetch_sudoku_puzzle(
+    app: AppHandle,
+    state: State<'_, HttpClientState>,
+    difficulty: Option<SudokuDifficulty>,
+    force_refresh: Option<bool>,
+) -> Result<SudokuData, String> {
+    let difficulty = difficulty.unwrap_or_default();
+    let cache = PuzzleCache::new(&app)?;
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let cache_key = format!("{today}-{}", difficulty.as_str());
+
+    if !force_refresh.unwrap_or(false) {
+        if let Some(cached) = cache.load::<SudokuData>(PuzzleType::Sudoku, &cache_key) {
+            info!("Serving {} Sudoku puzzle for {today} from cache", difficulty.as_str());
+            return Ok(cached);
+        }
+    }
+
+    info!("Fetching NYT {} Sudoku puzzle", difficulty.as_str());
+    let data = fetch_from_sources(&sudoku_sources(difficulty), &state.0).await?;
+    if let Err(e) = cache.store(PuzzleType::Sudoku, &cache_key, &data) {
+        warn!("Failed to cache {} Sudoku puzzle for {cache_key}: {e}", difficulty.as_str());
+    }
+    Ok(data)
 }
 
 
@@ -259,9 +449,40 @@ pub fn run() {
             .build(),
         )?;
       }
+      app.manage(HttpClientState(http::build_http_client()?));
+      diagnostics::init(app.handle());
       Ok(())
     })
-    .invoke_handler(tauri::generate_handler![fetch_wordle_answer, fetch_sudoku_puzzle])
+    .invoke_handler(tauri::generate_handler![fetch_wordle_answer, fetch_wordle_archive, fetch_sudoku_puzzle])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> chrono::NaiveDate {
+        chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn parse_wordle_date_resolves_today() {
+        assert_eq!(parse_wordle_date("Today", date(2024, 6, 15)), "2024-06-15");
+    }
+
+    #[test]
+    fn parse_wordle_date_resolves_yesterday() {
+        assert_eq!(parse_wordle_date("Yesterday", date(2024, 6, 15)), "2024-06-14");
+    }
+
+    #[test]
+    fn parse_wordle_date_parses_explicit_date() {
+        assert_eq!(parse_wordle_date("June 1, 2024", date(2024, 6, 15)), "2024-06-01");
+    }
+
+    #[test]
+    fn parse_wordle_date_falls_back_to_raw_text_on_unrecognized_format() {
+        assert_eq!(parse_wordle_date("???", date(2024, 6, 15)), "???");
+    }
+}
@@ -0,0 +1,32 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use log::{error, warn};
+use reqwest::Client;
+
+/// A single upstream that can produce a puzzle of type `T`.
+pub trait PuzzleSource<T>: Send + Sync {
+    /// Human-readable name used in fallback logging.
+    fn name(&self) -> &'static str;
+
+    fn fetch<'a>(&'a self, client: &'a Client) -> Pin<Box<dyn Future<Output = Result<T, String>> + Send + 'a>>;
+}
+
+/// Tries each source in priority order, returning the first success and logging
+/// every failure along the way.
+pub async fn fetch_from_sources<T>(sources: &[Box<dyn PuzzleSource<T>>], client: &Client) -> Result<T, String> {
+    let mut last_err = "No puzzle sources configured".to_string();
+
+    for source in sources {
+        match source.fetch(client).await {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                warn!("Puzzle source '{}' failed: {e}", source.name());
+                last_err = e;
+            }
+        }
+    }
+
+    error!("All puzzle sources exhausted");
+    Err(last_err)
+}